@@ -1,20 +1,15 @@
 use crate::controllers::frontend_prelude::*;
 
 use crate::models::{CrateOwner, OwnerKind, User};
-use crate::schema::{crate_owners, crates, users};
-use crate::util::errors::ChainError;
+use crate::schema::{crate_owners, crates};
+use crate::util::errors::{not_found, ChainError};
 use crate::views::EncodablePublicUser;
 
 /// Handles the `GET /users/:user_id` route.
 pub fn show(req: &mut dyn RequestExt) -> EndpointResult {
-    use self::users::dsl::{gh_login, id, users};
-
-    let name = crate::lower(&req.params()["user_id"]);
     let conn = req.db_conn()?;
-    let user: User = users
-        .filter(crate::lower(gh_login).eq(name))
-        .order(id.desc())
-        .first(&*conn)?;
+    let user = User::find_by_name_case_insensitive(&conn, &req.params()["user_id"])?
+        .ok_or_else(not_found)?;
 
     #[derive(Serialize)]
     struct R {
@@ -114,6 +114,22 @@ impl User {
         users::table.find(id).first(conn)
     }
 
+    /// Queries the database for a user by login, ignoring case.
+    ///
+    /// GitHub logins can be abandoned and taken over by another account, so several users may
+    /// share a login. In that case the most recently created user (highest `id`) is returned.
+    /// The lookup is backed by the `lower_gh_login` expression index.
+    pub fn find_by_name_case_insensitive(
+        conn: &PgConnection,
+        name: &str,
+    ) -> QueryResult<Option<User>> {
+        users::table
+            .filter(crate::lower(users::gh_login).eq(crate::lower(name)))
+            .order(users::id.desc())
+            .first(conn)
+            .optional()
+    }
+
     /// Queries the database for a user with a certain `api_token` value.
     pub fn find_by_api_token(conn: &PgConnection, token: &str) -> AppResult<User> {
         let api_token = ApiToken::find_by_api_token(conn, token)?;
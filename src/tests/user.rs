@@ -141,6 +141,39 @@ fn show() {
     assert_eq!(Some("https://github.com/Bar".into()), json.user.url);
 }
 
+#[test]
+fn show_nonexistent_user() {
+    let (_, anon) = TestApp::init().empty();
+    anon.get("/api/v1/users/foobar").assert_not_found();
+}
+
+#[test]
+fn find_by_name_case_insensitive() {
+    let (app, _) = TestApp::init().empty();
+    let emails = &app.as_inner().emails;
+
+    app.db(|conn| {
+        let bar = new_user("Bar")
+            .create_or_update(None, emails, conn)
+            .unwrap();
+        let found = User::find_by_name_case_insensitive(conn, "Bar").unwrap();
+        assert_eq!(Some(bar.id), found.map(|u| u.id));
+        let found = User::find_by_name_case_insensitive(conn, "bAR").unwrap();
+        assert_eq!(Some(bar.id), found.map(|u| u.id));
+
+        assert_none!(User::find_by_name_case_insensitive(conn, "baz").unwrap());
+
+        new_user("foo")
+            .create_or_update(None, emails, conn)
+            .unwrap();
+        let latest = new_user("FOO")
+            .create_or_update(None, emails, conn)
+            .unwrap();
+        let found = User::find_by_name_case_insensitive(conn, "foo").unwrap();
+        assert_eq!(Some(latest.id), found.map(|u| u.id));
+    });
+}
+
 #[test]
 fn show_latest_user_case_insensitively() {
     let (app, anon) = TestApp::init().empty();
@@ -6,6 +6,7 @@ use serde::Serialize;
 pub use self::io_util::{read_fill, read_le_u32, LimitErrorReader};
 pub use self::request_helpers::*;
 pub use self::request_proxy::RequestProxy;
+pub use self::token::generate_unambiguous_alphanumeric_string;
 
 pub mod errors;
 mod io_util;
@@ -87,9 +87,25 @@ impl std::ops::Deref for NewSecureToken {
 fn generate_secure_alphanumeric_string(len: usize) -> String {
     const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
 
+    generate_alphanumeric_string(CHARS, len)
+}
+
+/// Generates a random alphanumeric string that excludes the visually ambiguous characters
+/// `0`, `O`, `1`, `l` and `I`, for tokens that may be read aloud or transcribed by hand.
+///
+/// The reduced alphabet has 57 characters, giving log2(57) ≈ 5.83 bits of entropy per
+/// character instead of log2(62) ≈ 5.95. High-entropy secrets like API tokens should keep
+/// using the full alphabet.
+pub fn generate_unambiguous_alphanumeric_string(len: usize) -> String {
+    const CHARS: &[u8] = b"abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+    generate_alphanumeric_string(CHARS, len)
+}
+
+fn generate_alphanumeric_string(chars: &[u8], len: usize) -> String {
     OsRng
-        .sample_iter(Uniform::from(0..CHARS.len()))
-        .map(|idx| CHARS[idx] as char)
+        .sample_iter(Uniform::from(0..chars.len()))
+        .map(|idx| chars[idx] as char)
         .take(len)
         .collect()
 }
@@ -179,6 +195,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unambiguous_alphanumeric_string() {
+        for _ in 0..1000 {
+            let s = generate_unambiguous_alphanumeric_string(32);
+            assert_eq!(s.len(), 32);
+            assert!(!s.contains(&['0', 'O', '1', 'l', 'I'][..]), "{}", s);
+        }
+    }
+
     #[test]
     fn test_conflicting_prefixes() {
         // This sanity check prevents multiple tokens from starting with the same prefix, which